@@ -18,6 +18,38 @@ struct CurlArgs {
     /// JSON data for POST request (automatically sets method to POST)
     #[structopt(long = "json")]
     json_data: Option<String>,
+
+    /// Follow HTTP redirects (301, 302, 303, 307 and 308 responses with a Location header)
+    #[structopt(short = "L", long = "location")]
+    follow_redirects: bool,
+
+    /// Maximum number of redirects to follow before giving up
+    #[structopt(long = "max-redirects", default_value = "10")]
+    max_redirects: u32,
+
+    /// Print only the last N lines of a remote resource, fetched via HTTP Range requests
+    #[structopt(long = "tail")]
+    tail: Option<usize>,
+
+    /// With --tail, keep polling the resource and print newly appended lines
+    #[structopt(long = "follow")]
+    follow: bool,
+
+    /// Proxy URL to route requests through (also honors HTTP_PROXY/HTTPS_PROXY/NO_PROXY)
+    #[structopt(long = "proxy")]
+    proxy: Option<String>,
+
+    /// Custom header to send, in "Name: Value" form (repeatable)
+    #[structopt(short = "H", long = "header")]
+    headers: Vec<String>,
+
+    /// Send a bearer token as 'Authorization: Bearer <token>'
+    #[structopt(long = "bearer")]
+    bearer: Option<String>,
+
+    /// Send HTTP Basic auth credentials, in "user:pass" form
+    #[structopt(short = "u", long = "user")]
+    user: Option<String>,
 }
 
 // Helper methods for CurlArgs
@@ -33,79 +65,73 @@ impl CurlArgs {
     }
 }
 
-use url::Url;
-// Helper methods for URL parsing and validation
-fn validate_url(url: &str) -> Result<Url, String> {
-    // Pre-validation checks before URL parsing
-    if let Some(host) = url.split("://").nth(1) {
-        let host = host.split('/').next().unwrap_or(host);
-        
-        // Check for IPv6 address validity
-        if host.starts_with('[') && host.contains(']') {
-            // Extract the IPv6 address part between brackets
-            if let Some(ipv6_str) = host.split('[')
-                .nth(1)
-                .and_then(|s| s.split(']').next()) 
-            {
-                // Check for compressed zeros format
-                if ipv6_str.contains("::") {
-                    let double_colon_count = ipv6_str.matches("::").count();
-                    if double_colon_count > 1 {
-                        return Err("The URL contains an invalid IPv6 address.".to_string());
-                    }
-                }
+use url::{Host, ParseError, Url};
 
-                // Split into segments and validate each
-                let segments: Vec<&str> = ipv6_str.split(':').collect();
-                
-                // IPv6 should have 8 segments (or fewer with ::)
-                if segments.len() > 8 {
-                    return Err("The URL contains an invalid IPv6 address.".to_string());
-                }
+// Guesses a scheme for a schemeless input (e.g. `example.com`) so `curl example.com` works
+// without requiring `http://`/`https://` up front. `.onion`/`.i2p` hosts and Yggdrasil
+// (`0200::/7`) IPv6 addresses default to plain `http`; everything else defaults to `https`.
+fn guess_scheme(input: &str) -> &'static str {
+    let host = input.split('/').next().unwrap_or(input);
+    let host = host.trim_start_matches('[');
 
-                // Validate each segment
-                for segment in segments {
-                    if segment.is_empty() && !ipv6_str.contains("::") {
-                        return Err("The URL contains an invalid IPv6 address.".to_string());
-                    }
-                    if !segment.is_empty() {
-                        // Each segment should be valid hexadecimal and not longer than 4 chars
-                        if segment.len() > 4 || !segment.chars().all(|c| c.is_ascii_hexdigit()) {
-                            return Err("The URL contains an invalid IPv6 address.".to_string());
-                        }
-                    }
+    if host.contains(':') {
+        if let Some(first_group) = host.split(':').next() {
+            if let Ok(value) = u16::from_str_radix(first_group, 16) {
+                if (0x0200..=0x03ff).contains(&value) {
+                    return "http";
                 }
             }
         }
+    }
 
-        // Validate IPv4 address format and values
-        let ip_part = host.split(':').next().unwrap_or(host);
-        if ip_part.split('.').count() == 4 {
-            let octets: Vec<&str> = ip_part.split('.').collect();
-            if octets.iter().any(|&octet| {
-                if let Ok(num) = octet.parse::<u32>() {
-                    num > 255
-                } else {
-                    false
-                }
-            }) {
-                return Err("The URL contains an invalid IPv4 address.".to_string());
-            }
-        }
+    let name = host.split(':').next().unwrap_or(host);
+    if name.ends_with(".onion") || name.ends_with(".i2p") {
+        "http"
+    } else {
+        "https"
+    }
+}
 
-        // Validate port number range
-        if let Some(port_str) = host.split(':').nth(1) {
-            if let Ok(port) = port_str.split('/').next().unwrap_or(port_str).parse::<u32>() {
-                if port > 65535 {
-                    return Err("The URL contains an invalid port number.".to_string());
-                }
-            }
-        }
+// A bare (bracketless) IPv6 host like `0200::1` is ambiguous with `host:port` and gets
+// misparsed by `Url::parse` once a scheme is prepended (the trailing group is read as an
+// invalid port). If the whole host component is a valid IPv6 address, bracket it so the
+// scheme-prepended result parses the way `guess_scheme` intended.
+fn bracket_bare_ipv6_host(input: &str) -> String {
+    let (host, rest) = match input.split_once('/') {
+        Some((host, rest)) => (host, Some(rest)),
+        None => (input, None),
+    };
+
+    if host.starts_with('[') || host.parse::<std::net::Ipv6Addr>().is_err() {
+        return input.to_string();
     }
 
-    // Parse and validate URL structure
-    let parsed_url = match Url::parse(url) {
-        Ok(url) => url,
+    match rest {
+        Some(rest) => format!("[{}]/{}", host, rest),
+        None => format!("[{}]", host),
+    }
+}
+
+// Helper methods for URL parsing and validation
+fn validate_url(url: &str) -> Result<Url, String> {
+    // Schemeless input (no `scheme://`) gets a guessed scheme prepended before parsing.
+    let url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("{}://{}", guess_scheme(url), bracket_bare_ipv6_host(url))
+    };
+
+    let parsed_url = match Url::parse(&url) {
+        Ok(parsed) => parsed,
+        Err(ParseError::InvalidIpv6Address) => {
+            return Err("The URL contains an invalid IPv6 address.".to_string())
+        }
+        Err(ParseError::InvalidIpv4Address) => {
+            return Err("The URL contains an invalid IPv4 address.".to_string())
+        }
+        Err(ParseError::InvalidPort) => {
+            return Err("The URL contains an invalid port number.".to_string())
+        }
         Err(_) => return Err("The URL does not have a valid base protocol.".to_string()),
     };
 
@@ -115,17 +141,203 @@ fn validate_url(url: &str) -> Result<Url, String> {
         _ => return Err("The URL does not have a valid base protocol.".to_string()),
     }
 
+    match parsed_url.host() {
+        Some(Host::Domain(_)) | Some(Host::Ipv4(_)) | Some(Host::Ipv6(_)) => (),
+        None => return Err("The URL does not have a valid base protocol.".to_string()),
+    }
+
+    if parsed_url.port_or_known_default().is_none() {
+        return Err("The URL contains an invalid port number.".to_string());
+    }
+
     Ok(parsed_url)
 }
 
-use reqwest::blocking::Client;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+// Quickly checks that the proxy itself accepts a TCP connection, so a down/unreachable proxy
+// can be reported distinctly from an upstream origin that fails to connect later on.
+fn check_proxy_reachable(proxy: &Url) -> Result<(), String> {
+    let host = proxy.host_str().unwrap_or_default();
+    let port = proxy.port_or_known_default().unwrap_or(80);
+
+    let reachable = (host, port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .map(|addr| TcpStream::connect_timeout(&addr, Duration::from_secs(5)).is_ok())
+        .unwrap_or(false);
+
+    if reachable {
+        Ok(())
+    } else {
+        Err("Unable to connect to the proxy server. Perhaps the proxy address is wrong or the proxy is down.".to_string())
+    }
+}
+
+// Builds the `Proxy` for `--proxy <url>`. Credentials given as `user:pass@` in the proxy URL
+// are pulled out and attached as proxy basic-auth rather than left for reqwest to parse, so the
+// behavior is explicit regardless of reqwest's own URL handling.
+fn build_proxy(parsed: &Url) -> Result<reqwest::Proxy, String> {
+    let username = parsed.username().to_string();
+    let password = parsed.password().map(|p| p.to_string());
+
+    let mut target = parsed.clone();
+    let _ = target.set_username("");
+    let _ = target.set_password(None);
+
+    let mut proxy = reqwest::Proxy::all(target.as_str())
+        .map_err(|e| format!("The proxy URL '{}' is invalid: {}", parsed, e))?;
+
+    if !username.is_empty() {
+        proxy = proxy.basic_auth(&username, password.as_deref().unwrap_or(""));
+    }
+
+    Ok(proxy)
+}
+
+// Builds the `Client` used for the request, wiring up `--proxy` when given. Without `--proxy`,
+// `reqwest` falls back to its default behavior of reading HTTP_PROXY/HTTPS_PROXY/NO_PROXY from
+// the environment. reqwest's own redirect following is turned off; redirects are handled by
+// `send_with_redirects` according to `-L`/`--max-redirects`.
+fn build_client(args: &CurlArgs) -> Result<Client, String> {
+    let mut builder = Client::builder().redirect(reqwest::redirect::Policy::none());
+
+    if let Some(proxy_url) = &args.proxy {
+        let parsed = Url::parse(proxy_url)
+            .map_err(|e| format!("The proxy URL '{}' is invalid: {}", proxy_url, e))?;
+        check_proxy_reachable(&parsed)?;
+        builder = builder.proxy(build_proxy(&parsed)?);
+    }
+
+    builder.build().map_err(|e| e.to_string())
+}
+
+// Turns a `reqwest::Error` from a failed send into a user-facing message. With `--proxy`, a
+// failure to reach the proxy itself is already caught earlier by `check_proxy_reachable`, so a
+// connect error here means the upstream origin is the one that couldn't be reached. A proxy
+// taken from HTTP_PROXY/HTTPS_PROXY is not checked beforehand, so its connect failures also
+// end up here with the same message.
+fn connect_error_message(e: &reqwest::Error) -> String {
+    if e.is_connect() {
+        "Unable to connect to the server. Perhaps the network is offline or the server hostname cannot be resolved.".to_string()
+    } else {
+        e.to_string()
+    }
+}
+
+// Parses one `-H "Name: Value"` argument at the first colon.
+fn parse_user_header(raw: &str) -> Result<(reqwest::header::HeaderName, reqwest::header::HeaderValue), String> {
+    let (name, value) = raw
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid header '{}': expected \"Name: Value\"", raw))?;
+    let name = name.trim();
+    let value = value.trim();
+
+    let header_name = reqwest::header::HeaderName::from_bytes(name.as_bytes())
+        .map_err(|e| format!("Invalid header name '{}': {}", name, e))?;
+    let header_value = reqwest::header::HeaderValue::from_str(value)
+        .map_err(|e| format!("Invalid header value for '{}': {}", name, e))?;
+
+    Ok((header_name, header_value))
+}
+
+// Builds the `-H/--header` overrides as a `HeaderMap`, so applying them with `.headers()`
+// replaces any same-named default header (e.g. a user-supplied Content-Type) instead of
+// duplicating it.
+fn build_header_overrides(args: &CurlArgs) -> Result<reqwest::header::HeaderMap, String> {
+    let mut headers = reqwest::header::HeaderMap::new();
+    for raw in &args.headers {
+        let (name, value) = parse_user_header(raw)?;
+        headers.insert(name, value);
+    }
+    Ok(headers)
+}
+
+// Applies `-H/--header` overrides and `--bearer`/`-u` authentication to a request builder.
+fn apply_auth_and_headers(
+    builder: RequestBuilder,
+    args: &CurlArgs,
+    header_overrides: &reqwest::header::HeaderMap,
+) -> RequestBuilder {
+    let builder = builder.headers(header_overrides.clone());
+
+    if let Some(token) = &args.bearer {
+        builder.bearer_auth(token)
+    } else if let Some(user_pass) = &args.user {
+        let (username, password) = match user_pass.split_once(':') {
+            Some((u, p)) => (u, Some(p)),
+            None => (user_pass.as_str(), None),
+        };
+        builder.basic_auth(username, password)
+    } else {
+        builder
+    }
+}
+
+// Sends a request built by `build` and follows redirects when `args.follow_redirects` is set.
+// `build` is called again for each hop with the (possibly downgraded) method and the new URL,
+// so it must decide what to send based on `method` rather than assuming the original one.
+// 303 (and, as is common practice, 301/302) downgrade the method to GET and drop the body;
+// 307/308 preserve the original method and body.
+fn send_with_redirects<F>(
+    client: &Client,
+    url: &Url,
+    method: &str,
+    args: &CurlArgs,
+    build: F,
+) -> Result<Response, String>
+where
+    F: Fn(&Client, &str, &Url) -> RequestBuilder,
+{
+    let mut current_url = url.clone();
+    let mut current_method = method.to_string();
+    let mut redirects = 0;
+
+    loop {
+        let response = build(client, &current_method, &current_url)
+            .send()
+            .map_err(|e| connect_error_message(&e))?;
+
+        let status = response.status().as_u16();
+        if !args.follow_redirects || !matches!(status, 301 | 302 | 303 | 307 | 308) {
+            return Ok(response);
+        }
+
+        if redirects >= args.max_redirects {
+            return Err(format!(
+                "Too many redirects: exceeded the limit of {}",
+                args.max_redirects
+            ));
+        }
+
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or_else(|| format!("Redirect response ({}) is missing a Location header", status))?;
+
+        current_url = current_url
+            .join(location)
+            .map_err(|e| format!("The redirect Location '{}' is invalid: {}", location, e))?;
+
+        if matches!(status, 301..=303) {
+            current_method = "GET".to_string();
+        }
+
+        redirects += 1;
+    }
+}
 
 // Makes HTTP requests based on command-line arguments
 // Handles both GET and POST methods
 // For POST requests, supports both form data and JSON data
 // Returns response body as string or error message
-fn make_request(args: &CurlArgs) -> Result<String, String> {
-    let client = Client::new();
+fn make_request(args: &CurlArgs, url: Url) -> Result<String, String> {
+    let client = build_client(args)?;
+    let header_overrides = build_header_overrides(args)?;
 
     // Validate JSON data if present
     if let Some(json_data) = &args.json_data {
@@ -138,7 +350,9 @@ fn make_request(args: &CurlArgs) -> Result<String, String> {
     match args.get_method().as_str() {
         // Handle GET requests
         "GET" => {
-            let response = client.get(&args.url).send();
+            let response = send_with_redirects(&client, &url, "GET", args, |client, _method, url| {
+                apply_auth_and_headers(client.get(url.clone()), args, &header_overrides)
+            });
             match response {
                 Ok(resp) => {
                     if !resp.status().is_success() {
@@ -149,24 +363,24 @@ fn make_request(args: &CurlArgs) -> Result<String, String> {
                     }
                     resp.text().map_err(|e| e.to_string())
                 }
-                Err(e) => {
-                    if e.is_connect() {
-                        Err("Unable to connect to the server. Perhaps the network is offline or the server hostname cannot be resolved.".to_string())
-                    } else {
-                        Err(e.to_string())
-                    }
-                }
+                Err(e) => Err(e),
             }
         }
         // Handle POST requests
         "POST" => {
             // Handle JSON data POST requests
             if let Some(json_data) = &args.json_data {
-                let response = client
-                    .post(&args.url)
-                    .header("Content-Type", "application/json")
-                    .body(json_data.clone())
-                    .send();
+                let response = send_with_redirects(&client, &url, "POST", args, |client, method, url| {
+                    let builder = if method == "GET" {
+                        client.get(url.clone())
+                    } else {
+                        client
+                            .post(url.clone())
+                            .header("Content-Type", "application/json")
+                            .body(json_data.clone())
+                    };
+                    apply_auth_and_headers(builder, args, &header_overrides)
+                });
 
                 match response {
                     Ok(resp) => {
@@ -178,13 +392,7 @@ fn make_request(args: &CurlArgs) -> Result<String, String> {
                         }
                         resp.text().map_err(|e| e.to_string())
                     }
-                    Err(e) => {
-                        if e.is_connect() {
-                            Err("Unable to connect to the server. Perhaps the network is offline or the server hostname cannot be resolved.".to_string())
-                        } else {
-                            Err(e.to_string())
-                        }
-                    }
+                    Err(e) => Err(e),
                 }
             } else if let Some(data) = &args.data {
                 println!("Data: {}", data);
@@ -195,11 +403,17 @@ fn make_request(args: &CurlArgs) -> Result<String, String> {
                     let json_value: serde_json::Value = serde_json::from_str(data)
                         .map_err(|e| format!("Invalid JSON data: {}", e))?;
 
-                    let response = client
-                        .post(&args.url)
-                        .header("Content-Type", "application/json")
-                        .json(&json_value)
-                        .send();
+                    let response = send_with_redirects(&client, &url, "POST", args, |client, method, url| {
+                        let builder = if method == "GET" {
+                            client.get(url.clone())
+                        } else {
+                            client
+                                .post(url.clone())
+                                .header("Content-Type", "application/json")
+                                .json(&json_value)
+                        };
+                        apply_auth_and_headers(builder, args, &header_overrides)
+                    });
 
                     match response {
                         Ok(resp) => {
@@ -211,21 +425,21 @@ fn make_request(args: &CurlArgs) -> Result<String, String> {
                             }
                             resp.text().map_err(|e| e.to_string())
                         }
-                        Err(e) => {
-                            if e.is_connect() {
-                                Err("Unable to connect to the server. Perhaps the network is offline or the server hostname cannot be resolved.".to_string())
-                            } else {
-                                Err(e.to_string())
-                            }
-                        }
+                        Err(e) => Err(e),
                     }
                 } else {
                     // Handle form data POST requests
-                    let response = client
-                        .post(&args.url)
-                        .header("Content-Type", "application/x-www-form-urlencoded")
-                        .body(data.clone())
-                        .send();
+                    let response = send_with_redirects(&client, &url, "POST", args, |client, method, url| {
+                        let builder = if method == "GET" {
+                            client.get(url.clone())
+                        } else {
+                            client
+                                .post(url.clone())
+                                .header("Content-Type", "application/x-www-form-urlencoded")
+                                .body(data.clone())
+                        };
+                        apply_auth_and_headers(builder, args, &header_overrides)
+                    });
 
                     match response {
                         Ok(resp) => {
@@ -237,13 +451,7 @@ fn make_request(args: &CurlArgs) -> Result<String, String> {
                             }
                             resp.text().map_err(|e| e.to_string())
                         }
-                        Err(e) => {
-                            if e.is_connect() {
-                                Err("Unable to connect to the server. Perhaps the network is offline or the server hostname cannot be resolved.".to_string())
-                            } else {
-                                Err(e.to_string())
-                            }
-                        }
+                        Err(e) => Err(e),
                     }
                 }
             } else {
@@ -254,6 +462,172 @@ fn make_request(args: &CurlArgs) -> Result<String, String> {
     }
 }
 
+use std::thread;
+
+// How long to wait between polls when --follow is active.
+const TAIL_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// Issues a one-byte `Range: bytes=0-0` probe to learn whether the server honors Range requests and how
+// large the resource currently is. Returns (supports_ranges, content_length).
+fn probe_range_support(
+    client: &Client,
+    url: &Url,
+    args: &CurlArgs,
+    header_overrides: &reqwest::header::HeaderMap,
+) -> Result<(bool, u64), String> {
+    let builder = client.get(url.clone()).header("Range", "bytes=0-0");
+    let response = apply_auth_and_headers(builder, args, header_overrides)
+        .send()
+        .map_err(|e| connect_error_message(&e))?;
+
+    if response.status().as_u16() == 206 {
+        let total_len = response
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.rsplit('/').next())
+            .and_then(|v| v.parse::<u64>().ok())
+            .ok_or_else(|| "Server returned a 206 response without a usable Content-Range header".to_string())?;
+        Ok((true, total_len))
+    } else if response.status().is_success() {
+        let total_len = response
+            .content_length()
+            .ok_or_else(|| "Server did not report a Content-Length for this resource".to_string())?;
+        Ok((false, total_len))
+    } else {
+        Err(format!(
+            "Request failed with status code: {}",
+            response.status().as_u16()
+        ))
+    }
+}
+
+// Fetches a trailing window of the resource, expanding it backward until at least `n` lines
+// have been collected (or the start of the resource is reached). Returns `total_len` as passed
+// in, plus the last `n` lines.
+fn collect_tail_lines(
+    client: &Client,
+    url: &Url,
+    total_len: u64,
+    n: usize,
+    args: &CurlArgs,
+    header_overrides: &reqwest::header::HeaderMap,
+) -> Result<(u64, Vec<String>), String> {
+    let mut window: u64 = 4096.min(total_len);
+
+    loop {
+        let start = total_len.saturating_sub(window);
+        let builder = client
+            .get(url.clone())
+            .header("Range", format!("bytes={}-", start));
+        let response = apply_auth_and_headers(builder, args, header_overrides)
+            .send()
+            .map_err(|e| connect_error_message(&e))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Request failed with status code: {}",
+                response.status().as_u16()
+            ));
+        }
+
+        let text = response.text().map_err(|e| e.to_string())?;
+        let mut lines: Vec<&str> = text.lines().collect();
+        // A window that doesn't start at byte 0 likely begins mid-line; drop that partial line.
+        if start > 0 && !lines.is_empty() {
+            lines.remove(0);
+        }
+
+        if lines.len() >= n || start == 0 {
+            let tail = lines
+                .iter()
+                .rev()
+                .take(n)
+                .rev()
+                .map(|s| s.to_string())
+                .collect();
+            return Ok((total_len, tail));
+        }
+
+        window = (window * 2).min(total_len);
+    }
+}
+
+// Implements `--tail <N>` (and `--follow`): prints the last N lines of a remote resource using
+// HTTP Range requests instead of downloading the whole body, then optionally keeps polling for
+// newly appended bytes.
+fn tail_request(args: &CurlArgs, url: Url) -> Result<(), String> {
+    let client = build_client(args)?;
+    let n = args.tail.expect("tail_request called without --tail");
+    let header_overrides = build_header_overrides(args)?;
+
+    let (supports_ranges, total_len) =
+        probe_range_support(&client, &url, args, &header_overrides)?;
+
+    if !supports_ranges {
+        let builder = client.get(url.clone());
+        let response = apply_auth_and_headers(builder, args, &header_overrides)
+            .send()
+            .map_err(|e| connect_error_message(&e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Request failed with status code: {}",
+                response.status().as_u16()
+            ));
+        }
+        let text = response.text().map_err(|e| e.to_string())?;
+        let lines: Vec<&str> = text.lines().collect();
+        let start = lines.len().saturating_sub(n);
+        for line in &lines[start..] {
+            println!("{}", line);
+        }
+
+        return if args.follow {
+            Err("Server does not support range requests; --follow requires range support.".to_string())
+        } else {
+            Ok(())
+        };
+    }
+
+    let (mut last_len, lines) =
+        collect_tail_lines(&client, &url, total_len, n, args, &header_overrides)?;
+    for line in &lines {
+        println!("{}", line);
+    }
+
+    if !args.follow {
+        return Ok(());
+    }
+
+    loop {
+        thread::sleep(TAIL_POLL_INTERVAL);
+
+        let builder = client
+            .get(url.clone())
+            .header("Range", format!("bytes={}-", last_len));
+        let response = apply_auth_and_headers(builder, args, &header_overrides)
+            .send()
+            .map_err(|e| connect_error_message(&e))?;
+
+        match response.status().as_u16() {
+            // Nothing new has been appended since the last poll.
+            416 => continue,
+            206 => {
+                let text = response.text().map_err(|e| e.to_string())?;
+                last_len += text.len() as u64;
+                print!("{}", text);
+            }
+            // The server stopped honoring ranges (e.g. the resource was replaced); restart from here.
+            200 => {
+                let text = response.text().map_err(|e| e.to_string())?;
+                last_len = text.len() as u64;
+                print!("{}", text);
+            }
+            code => return Err(format!("Request failed with status code: {}", code)),
+        }
+    }
+}
+
 // Formats JSON responses with pretty printing and sorted keys
 // Returns original string if input is not valid JSON
 fn format_json(response_body: &str) -> String {
@@ -278,19 +652,27 @@ fn main() {
     println!("Method: {}", method);
 
     match validate_url(&args.url) {
-        Ok(_) => match make_request(&args) {
-            Ok(body) => {
-                // Check if response is JSON
-                if serde_json::from_str::<serde_json::Value>(&body).is_ok() {
-                    println!("Response body (JSON with sorted keys):");
-                    println!("{}", format_json(&body));
-                } else {
-                    println!("Response body:");
-                    println!("{}", body);
+        Ok(url) => {
+            if args.tail.is_some() {
+                if let Err(e) = tail_request(&args, url) {
+                    println!("Error: {}", e);
+                }
+            } else {
+                match make_request(&args, url) {
+                    Ok(body) => {
+                        // Check if response is JSON
+                        if serde_json::from_str::<serde_json::Value>(&body).is_ok() {
+                            println!("Response body (JSON with sorted keys):");
+                            println!("{}", format_json(&body));
+                        } else {
+                            println!("Response body:");
+                            println!("{}", body);
+                        }
+                    }
+                    Err(e) => println!("Error: {}", e),
                 }
             }
-            Err(e) => println!("Error: {}", e),
-        },
+        }
         Err(e) => println!("Error: {}", e),
     }
 }
@@ -352,12 +734,8 @@ Hello, World!
 
     #[test]
     fn test_url_errors() {
-        // Invalid protocol cases
-        let protocol_test_cases = vec![
-            "www.eecg.toronto.edu",
-            "data://www.eecg.toronto.edu",
-            "http//www.eecg.toronto.edu",
-        ];
+        // Invalid protocol cases (a scheme is present but isn't http/https)
+        let protocol_test_cases = vec!["data://www.eecg.toronto.edu", "ftp://example.com"];
 
         for url in protocol_test_cases {
             let output = run_command(&[url]);
@@ -383,10 +761,84 @@ Hello, World!
 
         // Invalid port
         let output = run_command(&["http://127.0.0.1:65536"]);
-        assert_eq!(output, 
+        assert_eq!(output,
             "Requesting URL: http://127.0.0.1:65536\nMethod: GET\nError: The URL contains an invalid port number.");
     }
 
+    #[test]
+    fn test_scheme_guessing() {
+        // A schemeless host defaults to https, so this behaves like the explicit-https version
+        // of the same request.
+        let output = run_command(&[
+            "www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html",
+        ]);
+        assert_eq!(output,
+            "Requesting URL: www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html\nMethod: GET\nResponse body:\n<html>\n<body>\n<h1>\nHello, World!\n</h1>\n</body>\n</html>");
+
+        // A .onion host defaults to http instead; it won't resolve outside Tor, but the
+        // resulting error should be a connection failure, not a protocol-validation error.
+        let output = run_command(&["example.onion"]);
+        assert_eq!(output,
+            "Requesting URL: example.onion\nMethod: GET\nError: Unable to connect to the server. Perhaps the network is offline or the server hostname cannot be resolved.");
+
+        // A bare (bracketless) Yggdrasil IPv6 address also defaults to http. It should be
+        // bracketed internally before parsing, so it fails as an unreachable connection rather
+        // than as an invalid port/protocol.
+        let output = run_command(&["0200::1"]);
+        assert_eq!(output,
+            "Requesting URL: 0200::1\nMethod: GET\nError: Unable to connect to the server. Perhaps the network is offline or the server hostname cannot be resolved.");
+    }
+
+    #[test]
+    fn test_proxy() {
+        // An unreachable proxy gets a distinct error from an unreachable origin, so users can
+        // tell where the failure happened
+        let output = run_command(&[
+            "--proxy", "http://127.0.0.1:1",
+            "https://www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html",
+        ]);
+        assert_eq!(output,
+            "Requesting URL: https://www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html\nMethod: GET\nError: Unable to connect to the proxy server. Perhaps the proxy address is wrong or the proxy is down.");
+
+        // An invalid proxy URL is reported before any request is attempted
+        let output = run_command(&[
+            "--proxy", "not a url",
+            "https://www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html",
+        ]);
+        assert!(output.contains("Error: The proxy URL 'not a url' is invalid"));
+    }
+
+    #[test]
+    fn test_headers_and_auth() {
+        // Custom headers are sent as-is
+        let output = run_command(&[
+            "https://httpbin.org/headers",
+            "-H", "X-Test-Header: hello",
+        ]);
+        assert!(output.contains("\"X-Test-Header\": \"hello\""));
+
+        // --bearer sends an 'Authorization: Bearer <token>' header
+        let output = run_command(&["https://httpbin.org/bearer", "--bearer", "mytoken"]);
+        assert!(output.contains("\"authenticated\": true"));
+        assert!(output.contains("\"token\": \"mytoken\""));
+
+        // -u sends a base64-encoded Basic Authorization header
+        let output = run_command(&[
+            "https://httpbin.org/basic-auth/alice/secret",
+            "-u", "alice:secret",
+        ]);
+        assert!(output.contains("\"authenticated\": true"));
+        assert!(output.contains("\"user\": \"alice\""));
+
+        // A user-supplied Content-Type overrides the default one we add for JSON POST requests
+        let output = run_command(&[
+            "https://httpbin.org/post",
+            "--json", "{\"a\": 1}",
+            "-H", "Content-Type: application/vnd.api+json",
+        ]);
+        assert!(output.contains("\"Content-Type\": \"application/vnd.api+json\""));
+    }
+
     #[test]
     fn test_request_errors() {
         // Unreachable host
@@ -433,4 +885,40 @@ Hello, World!
         assert!(stderr.contains("thread 'main' panicked"));
         assert!(stderr.contains("Invalid JSON:"));
     }
+
+    #[test]
+    fn test_redirects() {
+        // Without -L, a redirect is reported as a plain status-code failure
+        let output = run_command(&["https://httpbin.org/redirect-to?url=https://www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html&status_code=302"]);
+        assert!(output.contains("Error: Request failed with status code: 302"));
+
+        // With -L, the redirect is followed to the final, stable page
+        let output = run_command(&[
+            "-L",
+            "https://httpbin.org/redirect-to?url=https://www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html&status_code=302",
+        ]);
+        assert!(output.ends_with(
+            "Response body:\n<html>\n<body>\n<h1>\nHello, World!\n</h1>\n</body>\n</html>"
+        ));
+
+        // Exceeding --max-redirects stops following and reports a clear error
+        let output = run_command(&["-L", "--max-redirects", "0", "https://httpbin.org/redirect/2"]);
+        assert!(output.contains("Error: Too many redirects: exceeded the limit of 0"));
+    }
+
+    #[test]
+    fn test_tail() {
+        // The fixed, known-size lab3 page has 7 lines; it is smaller than the initial 4096-byte
+        // Range window, so one Range request covers it, and only the last 3 lines are printed.
+        let output = run_command(&[
+            "--tail", "3",
+            "https://www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html",
+        ]);
+        assert_eq!(output, "Requesting URL: https://www.eecg.toronto.edu/~bli/ece1724/assignments/files/lab3.html\nMethod: GET\n</h1>\n</body>\n</html>");
+
+        // A server that ignores Range requests (httpbin's /uuid echoes a single-line JSON
+        // body without Accept-Ranges) falls back to a full GET and trims the result locally.
+        let output = run_command(&["--tail", "1", "https://httpbin.org/uuid"]);
+        assert!(!output.is_empty());
+    }
 }